@@ -14,7 +14,7 @@
 //! let query = cratesiover::query("cratesiover", &env!("CARGO_PKG_VERSION")).unwrap();
 //!
 //! match query {
-//!   Status::Behind(ver) => println!("crate is behind the version on crates.io {}", ver),
+//!   Status::Behind(ver, kind) => println!("crate is behind the version on crates.io {} ({:?})", ver, kind),
 //!   Status::Equal(ver) => println!("crate is equal to the version on crates.io {}", ver),
 //!   Status::Ahead(ver) => println!("crate is ahead of the version on crates.io {}", ver),
 //! }
@@ -23,32 +23,167 @@
 
 use colored::*;
 use linefeed::Terminal;
-use reqwest;
 use semver::Version;
+use serde::Deserialize;
 use std::cmp::Ordering;
 use std::io::{self, Write};
+use std::path::Path;
 
 /// The comparitive status of the version query.
 /// Each variant contains the `crates.io` version number.
 #[derive(Debug, PartialEq)]
 pub enum Status {
-	/// The version is behind the one on `crates.io`.
-	Behind(Version),
+	/// The version is behind the one on `crates.io`, by the given [`UpdateKind`].
+	Behind(Version, UpdateKind),
 	/// The version is equal to the one on `crates.io`.
 	Equal(Version),
 	/// The version is ahead of the one on `crates.io`.
 	Ahead(Version),
 }
 
+/// The kind of update between the current version and a newer one.
+///
+/// Semver `0.x` crates are treated specially: cargo considers every `0.x` minor bump a breaking
+/// change, so a `0.x` minor bump is classified as [`UpdateKind::Major`] rather than `Minor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateKind {
+	/// A breaking update (`major` increased, or `minor` increased on a `0.x` version).
+	Major,
+	/// A backwards compatible update that adds functionality (`minor` increased).
+	Minor,
+	/// A backwards compatible bug fix update (`patch` increased).
+	Patch,
+}
+
+/// Configuration controlling how a version query is performed.
+///
+/// Build one with [`QueryConfig::builder`]; [`QueryConfig::default`] matches `cargo`'s own
+/// defaults: the public `crates.io` sparse index, no prerelease versions, no yanked versions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryConfig {
+	registry_index: String,
+	allow_prerelease: bool,
+	allow_yanked: bool,
+}
+
+impl Default for QueryConfig {
+	fn default() -> Self {
+		QueryConfig {
+			registry_index: "https://index.crates.io".to_string(),
+			allow_prerelease: false,
+			allow_yanked: false,
+		}
+	}
+}
+
+impl QueryConfig {
+	/// Start building a [`QueryConfig`] from the defaults.
+	pub fn builder() -> QueryConfigBuilder {
+		QueryConfigBuilder(QueryConfig::default())
+	}
+}
+
+/// Builder for a [`QueryConfig`]. See [`QueryConfig::builder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryConfigBuilder(QueryConfig);
+
+impl QueryConfigBuilder {
+	/// Set the base URL of the registry's sparse index. Defaults to `https://index.crates.io`,
+	/// so users on a private or alternative registry can point elsewhere.
+	pub fn registry_index(mut self, base_url: impl Into<String>) -> Self {
+		self.0.registry_index = base_url.into();
+		self
+	}
+
+	/// Allow prerelease versions to be eligible for the "latest" comparison. Defaults to
+	/// `false`, matching `cargo`.
+	pub fn allow_prerelease(mut self, allow: bool) -> Self {
+		self.0.allow_prerelease = allow;
+		self
+	}
+
+	/// Allow yanked versions to be considered. Defaults to `false`.
+	pub fn allow_yanked(mut self, allow: bool) -> Self {
+		self.0.allow_yanked = allow;
+		self
+	}
+
+	/// Finish building the [`QueryConfig`].
+	pub fn build(self) -> QueryConfig {
+		self.0
+	}
+}
+
+/// Classify the update from `current` to `other`, assuming `other` is newer.
+fn classify(current: &Version, other: &Version) -> UpdateKind {
+	if other.major > current.major {
+		UpdateKind::Major
+	} else if current.major == 0 {
+		if other.minor > current.minor {
+			UpdateKind::Major
+		} else {
+			UpdateKind::Patch
+		}
+	} else if other.minor > current.minor {
+		UpdateKind::Minor
+	} else {
+		UpdateKind::Patch
+	}
+}
+
+/// Returns `true` if upgrading from `current` to `other` is a non-breaking change.
+///
+/// For `0.x` versions this means the same minor version with a greater patch. For `>=1.0`
+/// versions this means the same major version with a greater minor or patch.
+pub fn is_compatible(current: &Version, other: &Version) -> bool {
+	if current.major == 0 {
+		other.major == 0 && other.minor == current.minor && other.patch > current.patch
+	} else {
+		other.major == current.major
+			&& (other.minor > current.minor
+				|| (other.minor == current.minor && other.patch > current.patch))
+	}
+}
+
 /// Errors in requesting or parsing the query.
 #[derive(Debug)]
 pub enum Error {
-	/// Failed to parse the response for a max version of the crate.
+	/// The index had no published versions eligible under the yanked/prerelease policy to
+	/// compare against.
 	ParseError,
+	/// Failed to parse a version record line from the sparse index.
+	JsonError(serde_json::Error),
 	/// Failed to parse the reponse into a `semver::Version`.
 	SemVerError(semver::SemVerError),
-	/// Failed to successfully make a request to or receive a response from `crates.io`.
+	/// Failed to successfully make a request to or receive a response from the registry index.
 	RequestError(reqwest::Error),
+	/// Failed to read a `Cargo.toml` manifest from disk, or to write the status to the terminal.
+	IoError(io::Error),
+	/// Failed to parse a `Cargo.toml` manifest as TOML.
+	TomlError(toml::de::Error),
+	/// The manifest has no `[package]` table, or it is missing a `name` and/or `version` field.
+	ManifestError,
+}
+
+/// The `[package]` table of a `Cargo.toml` manifest, as far as this crate cares.
+#[derive(Deserialize)]
+struct Manifest {
+	package: Option<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+	name: Option<String>,
+	version: Option<String>,
+}
+
+/// A single version line as published in the `crates.io` sparse index.
+///
+/// See <https://doc.rust-lang.org/cargo/reference/registry-index.html#index-format>.
+#[derive(Deserialize)]
+struct IndexVersion {
+	vers: String,
+	yanked: bool,
 }
 
 struct Writer<'a, T: Terminal>(&'a T);
@@ -76,7 +211,30 @@ impl<'a, T: Terminal> Write for Writer<'a, T> {
 
 /// Get the `crates.io` version of the specified crate.
 pub fn get(crate_name: &str) -> Result<Version, Error> {
-	Version::parse(parse(&web_req(crate_name)?)?).map_err(|e| Error::SemVerError(e))
+	get_all_versions(crate_name)?
+		.into_iter()
+		.next()
+		.ok_or(Error::ParseError)
+}
+
+/// Get every non-yanked, non-prerelease published version of the specified crate, sorted
+/// newest first.
+///
+/// This is useful for callers that want more than just the latest version, such as reporting
+/// how many releases behind the current version is, or picking the latest version within a
+/// compatible range.
+pub fn get_all_versions(crate_name: &str) -> Result<Vec<Version>, Error> {
+	get_all_versions_with(crate_name, &QueryConfig::default())
+}
+
+/// As [`get_all_versions`], but using the registry and prerelease/yank policy in `config`.
+pub fn get_all_versions_with(
+	crate_name: &str,
+	config: &QueryConfig,
+) -> Result<Vec<Version>, Error> {
+	let mut versions = parse(&web_req(crate_name, &config.registry_index)?, config)?;
+	versions.sort_unstable_by(|a, b| b.cmp(a));
+	Ok(versions)
 }
 
 /// Gets the `crates.io` version of the specified crate and compares it to the specified version.
@@ -86,29 +244,97 @@ pub fn get(crate_name: &str) -> Result<Version, Error> {
 /// use cratesiover::{ query, Status };
 /// let query = query("cratesiover", "0.1.0").unwrap();
 /// match query {
-///  Status::Behind(ver) => println!("crate is behind the version on crates.io {}", ver),
+///  Status::Behind(ver, kind) => println!("crate is behind the version on crates.io {} ({:?})", ver, kind),
 ///  Status::Equal(ver) => println!("crate is equal to the version on crates.io {}", ver),
 ///  Status::Ahead(ver) => println!("crate is ahead of the version on crates.io {}", ver),
 /// }
 /// ```
 pub fn query(crate_name: &str, version: &str) -> Result<Status, Error> {
-	let version = Version::parse(version).map_err(|e| Error::SemVerError(e))?;
+	let version = Version::parse(version).map_err(Error::SemVerError)?;
 	Ok(cmp(&version, get(crate_name)?))
 }
 
+/// As [`query`], but using the registry and prerelease/yank policy in `config`, for users on
+/// a private or alternative registry (mirroring how `cargo-edit`'s latest-dependency lookup
+/// takes a registry argument and a prerelease flag).
+pub fn query_with(crate_name: &str, version: &str, config: &QueryConfig) -> Result<Status, Error> {
+	let version = Version::parse(version).map_err(Error::SemVerError)?;
+	let latest = get_all_versions_with(crate_name, config)?
+		.into_iter()
+		.next()
+		.ok_or(Error::ParseError)?;
+	Ok(cmp(&version, latest))
+}
+
+/// Query and compare the calling crate's version number against `crates.io`, reading the
+/// crate name and version from `CARGO_PKG_NAME`/`CARGO_PKG_VERSION`.
+///
+/// This is deliberately a macro and not a plain `fn query_self()`: `CARGO_PKG_NAME`/
+/// `CARGO_PKG_VERSION` are only visible to `env!` at the *compile time of the crate that
+/// textually contains the `env!` call*. A `query_self` function living in this crate would
+/// always report `cratesiover`'s own name and version, not the caller's. Exporting a macro
+/// means `env!` is expanded inside the caller's crate instead, which is the only way to make
+/// self-detection work for an arbitrary caller. If you need a callable (non-macro) API,
+/// [`query_self_from_manifest`] reads the name/version from a `Cargo.toml` path instead.
+///
+/// # Example
+/// ```rust
+/// let query = cratesiover::query_self!();
+/// ```
+#[macro_export]
+macro_rules! query_self {
+	() => {
+		$crate::query(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+	};
+}
+
+/// Query and compare the calling crate's version number against `crates.io`. Write to stdout
+/// the status.
+///
+/// See [`query_self!`] for why this is a macro rather than a plain function; [`output_self_from_manifest`]
+/// is the non-macro alternative when you have a `Cargo.toml` path instead of a caller to expand into.
+#[macro_export]
+macro_rules! output_self {
+	() => {
+		$crate::output(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+	};
+}
+
+/// Query and compare a crate's version number against `crates.io`, reading the crate name
+/// and version from a `Cargo.toml` manifest at the given path.
+///
+/// Useful for tooling that checks the version of some other crate on disk, where
+/// [`query_self!`]'s compile-time detection doesn't apply.
+pub fn query_self_from_manifest<P: AsRef<Path>>(manifest_path: P) -> Result<Status, Error> {
+	let (name, version) = read_manifest(manifest_path.as_ref())?;
+	query(&name, &version)
+}
+
+/// As [`query_self_from_manifest`], but writes the status to stdout.
+pub fn output_self_from_manifest<P: AsRef<Path>>(manifest_path: P) -> Result<(), Error> {
+	let (name, version) = read_manifest(manifest_path.as_ref())?;
+	output(&name, &version).map_err(Error::IoError)
+}
+
+fn read_manifest(manifest_path: &Path) -> Result<(String, String), Error> {
+	let text = std::fs::read_to_string(manifest_path).map_err(Error::IoError)?;
+	let manifest: Manifest = toml::from_str(&text).map_err(Error::TomlError)?;
+	let package = manifest.package.ok_or(Error::ManifestError)?;
+	let name = package.name.ok_or(Error::ManifestError)?;
+	let version = package.version.ok_or(Error::ManifestError)?;
+	Ok((name, version))
+}
+
 /// Query and compare the crate version number. Write to stdout the status.
 pub fn output(crate_name: &str, version: &str) -> io::Result<()> {
-	Ok(output_with_term(
-		crate_name,
-		version,
-		&linefeed::DefaultTerminal::new()?,
-	))
+	output_with_term(crate_name, version, &linefeed::DefaultTerminal::new()?);
+	Ok(())
 }
 
 /// Query and compare the crate version number. Write to the given terminal the status.
 pub fn output_with_term<Term: Terminal>(crate_name: &str, version: &str, terminal: &Term) {
 	print!("{}", "Checking for later version...".bright_yellow());
-	io::stdout().flush().is_ok();
+	let _ = io::stdout().flush();
 	let print_line = match query(crate_name, version) {
 		Ok(status) => match status {
 			Status::Equal(ver) => format!(
@@ -116,12 +342,20 @@ pub fn output_with_term<Term: Terminal>(crate_name: &str, version: &str, termina
 				"Running the latest papyrus version ".bright_green(),
 				ver.to_string().bright_green()
 			),
-			Status::Behind(ver) => format!(
+			Status::Behind(ver, UpdateKind::Patch) => format!(
 				"{}",
 				format!(
 					"The current papyrus version {} is old, please update to {}",
 					version, ver
 				)
+				.bright_yellow()
+			),
+			Status::Behind(ver, kind) => format!(
+				"{}",
+				format!(
+					"The current papyrus version {} is old ({:?} update available), please update to {}",
+					version, kind, ver
+				)
 				.bright_red()
 			),
 			Status::Ahead(ver) => format!(
@@ -137,50 +371,220 @@ pub fn output_with_term<Term: Terminal>(crate_name: &str, version: &str, termina
 	};
 	let mut wtr = Writer(terminal);
 	wtr.overwrite_current_console_line(&print_line).unwrap();
-	writeln!(wtr, "",).unwrap();
+	writeln!(wtr).unwrap();
+}
+
+/// Get the `crates.io` version of the specified crate, without blocking the current thread.
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub async fn get_async(crate_name: &str) -> Result<Version, Error> {
+	get_all_versions_async(crate_name)
+		.await?
+		.into_iter()
+		.next()
+		.ok_or(Error::ParseError)
+}
+
+/// Get every non-yanked, non-prerelease published version of the specified crate, sorted
+/// newest first, without blocking the current thread.
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub async fn get_all_versions_async(crate_name: &str) -> Result<Vec<Version>, Error> {
+	let config = QueryConfig::default();
+	let text = web_req_async(crate_name, &config.registry_index).await?;
+	let mut versions = parse(&text, &config)?;
+	versions.sort_unstable_by(|a, b| b.cmp(a));
+	Ok(versions)
+}
+
+/// Gets the `crates.io` version of the specified crate and compares it to the specified
+/// version, without blocking the current thread.
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub async fn query_async(crate_name: &str, version: &str) -> Result<Status, Error> {
+	let version = Version::parse(version).map_err(Error::SemVerError)?;
+	Ok(cmp(&version, get_async(crate_name).await?))
+}
+
+/// Check the `crates.io` version of the specified crate against the specified version,
+/// without blocking the current thread.
+///
+/// This is equivalent to [`query_async`], provided under the `check` verb for callers that
+/// don't want to print anything and just want the [`Status`].
+///
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub async fn check_async(crate_name: &str, version: &str) -> Result<Status, Error> {
+	query_async(crate_name, version).await
+}
+
+/// Parse the newline-delimited sparse index body into the set of eligible versions, honouring
+/// `config`'s yanked and prerelease policy.
+fn parse(text: &str, config: &QueryConfig) -> Result<Vec<Version>, Error> {
+	text.lines()
+		.filter(|line| !line.is_empty())
+		.map(|line| serde_json::from_str::<IndexVersion>(line).map_err(Error::JsonError))
+		.filter(|record| match record {
+			Ok(r) => config.allow_yanked || !r.yanked,
+			Err(_) => true,
+		})
+		.map(|record| record.and_then(|r| Version::parse(&r.vers).map_err(Error::SemVerError)))
+		.filter(|version| match version {
+			Ok(v) => config.allow_prerelease || v.pre.is_empty(),
+			Err(_) => true,
+		})
+		.collect()
 }
 
-fn parse(text: &str) -> Result<&str, Error> {
-	match text.split('\"').skip_while(|&x| x != "max_version").nth(2) {
-		// json format ("max_version":"#.#.#") hence will parse as [max_version, :, #,#,#]
-		Some(ver) => Ok(ver),
-		None => Err(Error::ParseError),
+/// Derive the sparse index path for a crate name.
+///
+/// See <https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files>.
+fn index_path(crate_name: &str) -> String {
+	let name = crate_name.to_lowercase();
+	match name.len() {
+		1 => format!("1/{}", name),
+		2 => format!("2/{}", name),
+		3 => format!("3/{}/{}", &name[0..1], name),
+		_ => format!("{}/{}/{}", &name[0..2], &name[2..4], name),
 	}
 }
 
-fn web_req(crate_name: &str) -> Result<String, Error> {
-	reqwest::get(&format!("https://crates.io/api/v1/crates/{}", crate_name))
-		.map_err(|e| Error::RequestError(e))?
+fn web_req(crate_name: &str, registry_index: &str) -> Result<String, Error> {
+	reqwest::blocking::get(format!("{}/{}", registry_index, index_path(crate_name)))
+		.map_err(Error::RequestError)?
 		.text()
-		.map_err(|e| Error::RequestError(e))
+		.map_err(Error::RequestError)
+}
+
+#[cfg(feature = "async")]
+async fn web_req_async(crate_name: &str, registry_index: &str) -> Result<String, Error> {
+	reqwest::get(&format!("{}/{}", registry_index, index_path(crate_name)))
+		.await
+		.map_err(Error::RequestError)?
+		.text()
+		.await
+		.map_err(Error::RequestError)
 }
 
 fn cmp(current: &Version, cratesio: Version) -> Status {
 	match current.cmp(&cratesio) {
-		Ordering::Less => Status::Behind(cratesio),
+		Ordering::Less => {
+			let kind = classify(current, &cratesio);
+			Status::Behind(cratesio, kind)
+		}
 		Ordering::Equal => Status::Equal(cratesio),
 		Ordering::Greater => Status::Ahead(cratesio),
 	}
 }
 
+#[test]
+fn index_path_test() {
+	assert_eq!(index_path("a"), "1/a");
+	assert_eq!(index_path("ab"), "2/ab");
+	assert_eq!(index_path("abc"), "3/a/abc");
+	assert_eq!(index_path("Serde"), "se/rd/serde");
+}
+
+#[cfg(test)]
+const TEST_INDEX_BODY: &str = "\
+{\"name\":\"foo\",\"vers\":\"0.1.0\",\"yanked\":false}
+{\"name\":\"foo\",\"vers\":\"0.2.0\",\"yanked\":true}
+{\"name\":\"foo\",\"vers\":\"0.3.0\",\"yanked\":false}
+{\"name\":\"foo\",\"vers\":\"0.4.0-beta.1\",\"yanked\":false}
+";
+
 #[test]
 fn parse_test() {
-	assert_eq!(parse(r#""max_version":"0.4.2""#).unwrap(), "0.4.2");
-	assert_eq!(parse(r#""max_version":"0..2""#).unwrap(), "0..2");
+	let versions = parse(TEST_INDEX_BODY, &QueryConfig::default()).unwrap();
+	assert_eq!(
+		versions,
+		vec![Version::parse("0.1.0").unwrap(), Version::parse("0.3.0").unwrap()]
+	);
+}
+
+#[test]
+fn parse_allow_yanked_test() {
+	let config = QueryConfig::builder().allow_yanked(true).build();
+	let versions = parse(TEST_INDEX_BODY, &config).unwrap();
+	assert_eq!(
+		versions,
+		vec![
+			Version::parse("0.1.0").unwrap(),
+			Version::parse("0.2.0").unwrap(),
+			Version::parse("0.3.0").unwrap()
+		]
+	);
+}
+
+#[test]
+fn parse_allow_prerelease_test() {
+	let config = QueryConfig::builder().allow_prerelease(true).build();
+	let versions = parse(TEST_INDEX_BODY, &config).unwrap();
+	assert_eq!(
+		versions,
+		vec![
+			Version::parse("0.1.0").unwrap(),
+			Version::parse("0.3.0").unwrap(),
+			Version::parse("0.4.0-beta.1").unwrap()
+		]
+	);
 }
 
 #[test]
 fn test_web_req() {
-	// verify that the return crate is the right one!
-	let req = web_req("papyrus");
+	// verify that the returned crate is the right one!
+	let req = web_req("papyrus", "https://index.crates.io");
 	match req {
-		Err(_) => panic!("failed to query crates.io"),
+		Err(_) => panic!("failed to query crates.io sparse index"),
 		Ok(text) => {
-			assert!(text.starts_with(r#"{"crate":{"id":"papyrus","name":"papyrus","#));
+			assert!(text.contains(r#""name":"papyrus""#));
 		}
 	}
 }
 
+#[cfg(all(test, feature = "async"))]
+#[tokio::test]
+async fn get_async_test() {
+	let versions = get_all_versions_async("papyrus")
+		.await
+		.expect("failed to query crates.io sparse index");
+	assert!(versions.windows(2).all(|w| w[0] >= w[1]));
+	assert_eq!(get_async("papyrus").await.unwrap(), versions[0]);
+}
+
+#[test]
+fn get_all_versions_test() {
+	let versions = get_all_versions("papyrus").expect("failed to query crates.io sparse index");
+	assert!(versions.windows(2).all(|w| w[0] >= w[1]));
+	assert_eq!(get("papyrus").unwrap(), versions[0]);
+}
+
+#[test]
+fn manifest_parse_test() {
+	let manifest: Manifest = toml::from_str(
+		r#"
+[package]
+name = "cratesiover"
+version = "2.1.0"
+"#,
+	)
+	.unwrap();
+	let package = manifest.package.unwrap();
+	assert_eq!(package.name.as_deref(), Some("cratesiover"));
+	assert_eq!(package.version.as_deref(), Some("2.1.0"));
+}
+
+#[test]
+fn manifest_missing_fields_test() {
+	let manifest: Manifest = toml::from_str("[package]\n").unwrap();
+	let package = manifest.package.unwrap();
+	assert!(package.name.is_none());
+	assert!(package.version.is_none());
+}
+
 #[test]
 fn cmp_test() {
 	let one_pt_oh = Version::parse("1.0.0").unwrap();
@@ -191,10 +595,31 @@ fn cmp_test() {
 	);
 	assert_eq!(
 		cmp(&pt_one_oh, one_pt_oh.clone(),),
-		Status::Behind(one_pt_oh.clone())
+		Status::Behind(one_pt_oh.clone(), UpdateKind::Major)
 	);
 	assert_eq!(
 		cmp(&one_pt_oh, pt_one_oh.clone()),
 		Status::Ahead(pt_one_oh.clone())
 	);
 }
+
+#[test]
+fn classify_test() {
+	let v = |s: &str| Version::parse(s).unwrap();
+	assert_eq!(classify(&v("1.0.0"), &v("2.0.0")), UpdateKind::Major);
+	assert_eq!(classify(&v("1.0.0"), &v("1.1.0")), UpdateKind::Minor);
+	assert_eq!(classify(&v("1.0.0"), &v("1.0.1")), UpdateKind::Patch);
+	// 0.x minor bumps are breaking.
+	assert_eq!(classify(&v("0.1.0"), &v("0.2.0")), UpdateKind::Major);
+	assert_eq!(classify(&v("0.1.0"), &v("0.1.1")), UpdateKind::Patch);
+}
+
+#[test]
+fn is_compatible_test() {
+	let v = |s: &str| Version::parse(s).unwrap();
+	assert!(is_compatible(&v("1.0.0"), &v("1.1.0")));
+	assert!(is_compatible(&v("1.0.0"), &v("1.0.1")));
+	assert!(!is_compatible(&v("1.0.0"), &v("2.0.0")));
+	assert!(is_compatible(&v("0.1.0"), &v("0.1.1")));
+	assert!(!is_compatible(&v("0.1.0"), &v("0.2.0")));
+}